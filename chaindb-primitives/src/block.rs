@@ -0,0 +1,45 @@
+use codec::{Decode, Encode};
+
+use crate::{BlockNumber, Hash, Header, Justifications};
+
+/// An extrinsic kept in its encoded form; the node core does not interpret it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+pub struct Extrinsic(pub Vec<u8>);
+
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct Block {
+    pub header: Header,
+    pub extrinsics: Vec<Extrinsic>,
+}
+
+impl Block {
+    pub fn hash(&self) -> Hash {
+        self.header.hash()
+    }
+}
+
+/// A block together with any justifications proving its finality.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct SignedBlock {
+    pub block: Block,
+    pub justifications: Option<Justifications>,
+}
+
+/// Identifies a block either by hash or by number on the canonical chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum BlockId {
+    Hash(Hash),
+    Number(BlockNumber),
+}
+
+impl From<Hash> for BlockId {
+    fn from(hash: Hash) -> Self {
+        BlockId::Hash(hash)
+    }
+}
+
+impl From<BlockNumber> for BlockId {
+    fn from(number: BlockNumber) -> Self {
+        BlockId::Number(number)
+    }
+}
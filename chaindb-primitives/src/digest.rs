@@ -0,0 +1,111 @@
+use codec::{Decode, Encode};
+
+/// Four-byte identifier of the consensus engine that produced a digest item
+/// or justification, e.g. `*b"aura"`.
+pub type ConsensusEngineId = [u8; 4];
+
+/// A single entry in a header digest.
+///
+/// Variant indices match the Substrate `DigestItem` layout so headers stay
+/// byte-compatible with Substrate-based tooling.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum DigestItem {
+    /// Opaque data not interpreted by consensus.
+    #[codec(index = 0)]
+    Other(Vec<u8>),
+    /// A message from the consensus engine to the chain, e.g. an authority
+    /// set change.
+    #[codec(index = 4)]
+    Consensus(ConsensusEngineId, Vec<u8>),
+    /// The block author's seal; always the last item in a sealed header.
+    #[codec(index = 5)]
+    Seal(ConsensusEngineId, Vec<u8>),
+    /// Data the author attaches before execution, e.g. the slot number.
+    #[codec(index = 6)]
+    PreRuntime(ConsensusEngineId, Vec<u8>),
+}
+
+impl DigestItem {
+    /// The engine this item belongs to, or `None` for [`DigestItem::Other`].
+    pub fn engine_id(&self) -> Option<ConsensusEngineId> {
+        match self {
+            DigestItem::Other(_) => None,
+            DigestItem::Consensus(id, _)
+            | DigestItem::Seal(id, _)
+            | DigestItem::PreRuntime(id, _) => Some(*id),
+        }
+    }
+
+    pub fn as_pre_runtime(&self) -> Option<(ConsensusEngineId, &[u8])> {
+        match self {
+            DigestItem::PreRuntime(id, data) => Some((*id, data)),
+            _ => None,
+        }
+    }
+
+    pub fn as_consensus(&self) -> Option<(ConsensusEngineId, &[u8])> {
+        match self {
+            DigestItem::Consensus(id, data) => Some((*id, data)),
+            _ => None,
+        }
+    }
+
+    pub fn as_seal(&self) -> Option<(ConsensusEngineId, &[u8])> {
+        match self {
+            DigestItem::Seal(id, data) => Some((*id, data)),
+            _ => None,
+        }
+    }
+}
+
+/// The ordered list of digest items carried by a header.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+pub struct Digest {
+    pub logs: Vec<DigestItem>,
+}
+
+impl Digest {
+    pub fn push(&mut self, item: DigestItem) {
+        self.logs.push(item);
+    }
+
+    pub fn logs(&self) -> &[DigestItem] {
+        &self.logs
+    }
+
+    /// The first pre-runtime payload from `engine_id`.
+    pub fn pre_runtime(&self, engine_id: ConsensusEngineId) -> Option<&[u8]> {
+        self.find(engine_id, DigestItem::as_pre_runtime)
+    }
+
+    /// The first consensus payload from `engine_id`.
+    pub fn consensus(&self, engine_id: ConsensusEngineId) -> Option<&[u8]> {
+        self.find(engine_id, DigestItem::as_consensus)
+    }
+
+    /// The seal, if the last item is one.
+    pub fn seal(&self) -> Option<(ConsensusEngineId, &[u8])> {
+        self.logs.last().and_then(DigestItem::as_seal)
+    }
+
+    /// Removes and returns the trailing seal, leaving the digest as it was
+    /// when the author signed it.
+    pub fn pop_seal(&mut self) -> Option<DigestItem> {
+        match self.logs.last() {
+            Some(DigestItem::Seal(..)) => self.logs.pop(),
+            _ => None,
+        }
+    }
+
+    fn find<'a>(
+        &'a self,
+        engine_id: ConsensusEngineId,
+        select: impl Fn(&'a DigestItem) -> Option<(ConsensusEngineId, &'a [u8])>,
+    ) -> Option<&'a [u8]> {
+        self.logs
+            .iter()
+            .filter_map(select)
+            .find(|(id, _)| *id == engine_id)
+            .map(|(_, data)| data)
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest as _};
+use codec::{Decode, Encode};
+
+/// A 256-bit hash, used for block hashes and trie roots.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, std::hash::Hash, Encode, Decode)]
+pub struct Hash(pub [u8; 32]);
+
+impl Hash {
+    pub const fn zero() -> Self {
+        Hash([0; 32])
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for Hash {
+    fn from(bytes: [u8; 32]) -> Self {
+        Hash(bytes)
+    }
+}
+
+impl AsRef<[u8]> for Hash {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Blake2b with a 256-bit output, the hashing function used for headers.
+pub fn blake2_256(data: &[u8]) -> Hash {
+    Hash(Blake2b::<U32>::digest(data).into())
+}
@@ -0,0 +1,38 @@
+use codec::{Decode, Encode};
+
+use crate::{blake2_256, Digest, Hash};
+
+pub type BlockNumber = u64;
+
+/// A block header. Its hash is the blake2-256 of the SCALE encoding.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct Header {
+    pub parent_hash: Hash,
+    #[codec(compact)]
+    pub number: BlockNumber,
+    pub state_root: Hash,
+    pub extrinsics_root: Hash,
+    pub digest: Digest,
+}
+
+impl Header {
+    pub fn new(
+        number: BlockNumber,
+        extrinsics_root: Hash,
+        state_root: Hash,
+        parent_hash: Hash,
+        digest: Digest,
+    ) -> Self {
+        Header {
+            parent_hash,
+            number,
+            state_root,
+            extrinsics_root,
+            digest,
+        }
+    }
+
+    pub fn hash(&self) -> Hash {
+        blake2_256(&self.encode())
+    }
+}
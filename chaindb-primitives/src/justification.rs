@@ -0,0 +1,49 @@
+use codec::{Decode, Encode};
+
+use crate::ConsensusEngineId;
+
+/// A justification as produced by its consensus engine, kept opaque here.
+pub type EncodedJustification = Vec<u8>;
+
+/// A justification tagged with the engine that can verify it.
+pub type Justification = (ConsensusEngineId, EncodedJustification);
+
+/// All justifications known for a block, at most one per engine.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct Justifications(Vec<Justification>);
+
+impl Justifications {
+    pub fn iter(&self) -> impl Iterator<Item = &Justification> {
+        self.0.iter()
+    }
+
+    /// Adds a justification, returning `false` if one from the same engine
+    /// is already present.
+    pub fn append(&mut self, justification: Justification) -> bool {
+        if self.get(justification.0).is_some() {
+            return false;
+        }
+        self.0.push(justification);
+        true
+    }
+
+    pub fn get(&self, engine_id: ConsensusEngineId) -> Option<&EncodedJustification> {
+        self.0
+            .iter()
+            .find(|(id, _)| *id == engine_id)
+            .map(|(_, data)| data)
+    }
+
+    pub fn into_justification(self, engine_id: ConsensusEngineId) -> Option<EncodedJustification> {
+        self.0
+            .into_iter()
+            .find(|(id, _)| *id == engine_id)
+            .map(|(_, data)| data)
+    }
+}
+
+impl From<Justification> for Justifications {
+    fn from(justification: Justification) -> Self {
+        Justifications(vec![justification])
+    }
+}
@@ -0,0 +1,21 @@
+//! Core chain types shared by networking, storage and RPC.
+//!
+//! Every type here has a canonical SCALE encoding. The byte layout is part of
+//! the wire and on-disk format, so changes must keep the golden vectors in
+//! `tests/codec.rs` passing.
+
+mod block;
+mod digest;
+mod hash;
+mod header;
+mod justification;
+mod registry;
+
+pub use block::{Block, BlockId, Extrinsic, SignedBlock};
+pub use digest::{ConsensusEngineId, Digest, DigestItem};
+pub use hash::{blake2_256, Hash};
+pub use header::{BlockNumber, Header};
+pub use justification::{EncodedJustification, Justification, Justifications};
+pub use registry::{DigestError, DigestExtension, DigestRegistry};
+
+pub use codec::{Decode, Encode};
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{ConsensusEngineId, DigestItem, Header};
+
+/// Handles the digest items of one consensus engine or subsystem.
+///
+/// Extensions attach data by pushing `PreRuntime`, `Consensus` or `Seal`
+/// items tagged with their [`engine_id`](Self::engine_id), and are asked to
+/// verify every such item found in an imported header.
+pub trait DigestExtension: Send + Sync {
+    fn engine_id(&self) -> ConsensusEngineId;
+
+    /// Checks a single item belonging to this extension. `header` is the full
+    /// header the item was found in, seal included.
+    fn verify(&self, header: &Header, item: &DigestItem) -> Result<(), String>;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DigestError {
+    /// An extension for this engine is already registered.
+    AlreadyRegistered(ConsensusEngineId),
+    /// A header carries an item from an engine nobody registered.
+    UnknownEngine(ConsensusEngineId),
+    /// A seal appears somewhere other than the last position.
+    MisplacedSeal { index: usize },
+    /// The owning extension rejected an item.
+    Rejected {
+        engine_id: ConsensusEngineId,
+        index: usize,
+        reason: String,
+    },
+}
+
+impl fmt::Display for DigestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DigestError::AlreadyRegistered(id) => {
+                write!(f, "digest extension {} already registered", EngineId(id))
+            }
+            DigestError::UnknownEngine(id) => {
+                write!(f, "no digest extension registered for {}", EngineId(id))
+            }
+            DigestError::MisplacedSeal { index } => {
+                write!(f, "seal at digest index {index} is not the last item")
+            }
+            DigestError::Rejected {
+                engine_id,
+                index,
+                reason,
+            } => write!(
+                f,
+                "{} rejected digest item {index}: {reason}",
+                EngineId(engine_id)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DigestError {}
+
+/// Dispatches header digest items to the extension registered for their
+/// engine, so new engines can be added without touching [`Header`].
+#[derive(Default)]
+pub struct DigestRegistry {
+    extensions: HashMap<ConsensusEngineId, Box<dyn DigestExtension>>,
+}
+
+impl DigestRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, extension: Box<dyn DigestExtension>) -> Result<(), DigestError> {
+        let engine_id = extension.engine_id();
+        if self.extensions.contains_key(&engine_id) {
+            return Err(DigestError::AlreadyRegistered(engine_id));
+        }
+        self.extensions.insert(engine_id, extension);
+        Ok(())
+    }
+
+    pub fn get(&self, engine_id: ConsensusEngineId) -> Option<&dyn DigestExtension> {
+        self.extensions.get(&engine_id).map(|ext| ext.as_ref())
+    }
+
+    /// Verifies every engine-tagged item in `header`. `Other` items are
+    /// skipped; items from unregistered engines are an error.
+    pub fn verify(&self, header: &Header) -> Result<(), DigestError> {
+        let logs = header.digest.logs();
+        for (index, item) in logs.iter().enumerate() {
+            if matches!(item, DigestItem::Seal(..)) && index + 1 != logs.len() {
+                return Err(DigestError::MisplacedSeal { index });
+            }
+            let Some(engine_id) = item.engine_id() else {
+                continue;
+            };
+            let extension = self
+                .extensions
+                .get(&engine_id)
+                .ok_or(DigestError::UnknownEngine(engine_id))?;
+            extension
+                .verify(header, item)
+                .map_err(|reason| DigestError::Rejected {
+                    engine_id,
+                    index,
+                    reason,
+                })?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays an engine id as text when printable, hex otherwise.
+struct EngineId<'a>(&'a ConsensusEngineId);
+
+impl fmt::Display for EngineId<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match std::str::from_utf8(self.0) {
+            Ok(s) if s.chars().all(|c| c.is_ascii_graphic()) => write!(f, "engine {s:?}"),
+            _ => write!(
+                f,
+                "engine 0x{}",
+                self.0
+                    .iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<String>()
+            ),
+        }
+    }
+}
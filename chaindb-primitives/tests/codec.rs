@@ -0,0 +1,137 @@
+//! Golden vectors for the canonical SCALE encoding of chain types.
+//!
+//! These bytes are part of the wire and storage format. A failing test here
+//! means a change breaks compatibility with previously written data or with
+//! older peers.
+
+use chaindb_primitives::{
+    blake2_256, Block, BlockId, Decode, Digest, DigestItem, Encode, Extrinsic, Hash, Header,
+    Justifications, SignedBlock,
+};
+
+fn assert_golden<T: Encode + Decode + PartialEq + std::fmt::Debug>(value: &T, expected: &str) {
+    let expected = hex::decode(expected).unwrap();
+    assert_eq!(value.encode(), expected);
+    assert_eq!(&T::decode(&mut &expected[..]).unwrap(), value);
+}
+
+fn header() -> Header {
+    Header {
+        parent_hash: Hash([0x11; 32]),
+        number: 64,
+        state_root: Hash([0x22; 32]),
+        extrinsics_root: Hash([0x33; 32]),
+        digest: Digest::default(),
+    }
+}
+
+const HEADER: &str = concat!(
+    "1111111111111111111111111111111111111111111111111111111111111111",
+    "0101",
+    "2222222222222222222222222222222222222222222222222222222222222222",
+    "3333333333333333333333333333333333333333333333333333333333333333",
+    "00",
+);
+
+#[test]
+fn hash_is_raw_bytes() {
+    assert_golden(&Hash([0xab; 32]), &"ab".repeat(32));
+}
+
+#[test]
+fn blake2_256_matches_reference() {
+    assert_eq!(
+        blake2_256(b"").to_string(),
+        "0x0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+    );
+}
+
+#[test]
+fn digest_items() {
+    assert_golden(&DigestItem::Other(vec![0xff]), "0004ff");
+    assert_golden(&DigestItem::Consensus(*b"aura", vec![7]), "04617572610407");
+    assert_golden(&DigestItem::Seal(*b"aura", vec![]), "056175726100");
+    assert_golden(
+        &DigestItem::PreRuntime(*b"aura", vec![1, 2]),
+        "0661757261080102",
+    );
+}
+
+#[test]
+fn digest() {
+    let digest = Digest {
+        logs: vec![
+            DigestItem::PreRuntime(*b"aura", vec![1, 2]),
+            DigestItem::Seal(*b"aura", vec![]),
+        ],
+    };
+    assert_golden(&digest, "080661757261080102056175726100");
+}
+
+#[test]
+fn header_encoding_and_hash() {
+    let header = header();
+    assert_golden(&header, HEADER);
+    assert_eq!(
+        header.hash().to_string(),
+        "0xe1569892b1aebd698d30ab9130dd3284d3cd8b45f7266bfee06e4b9501b74ba5"
+    );
+}
+
+#[test]
+fn header_number_is_compact() {
+    let mut header = header();
+    header.number = 1;
+    assert_eq!(header.encode()[32], 0x04);
+    assert_eq!(header.encoded_size(), 32 + 1 + 32 + 32 + 1);
+}
+
+#[test]
+fn block_id() {
+    assert_golden(
+        &BlockId::Hash(Hash([0x44; 32])),
+        &format!("00{}", "44".repeat(32)),
+    );
+    assert_golden(&BlockId::Number(5), "010500000000000000");
+}
+
+#[test]
+fn block() {
+    let block = Block {
+        header: header(),
+        extrinsics: vec![Extrinsic(vec![0xaa, 0xbb])],
+    };
+    assert_golden(&block, &format!("{HEADER}0408aabb"));
+    assert_eq!(block.hash(), block.header.hash());
+}
+
+#[test]
+fn justifications() {
+    let mut justifications = Justifications::from((*b"FRNK", vec![1]));
+    assert_golden(&justifications, "0446524e4b0401");
+
+    assert!(!justifications.append((*b"FRNK", vec![2])));
+    assert!(justifications.append((*b"BEEF", vec![])));
+    assert_eq!(justifications.get(*b"FRNK"), Some(&vec![1]));
+    assert_eq!(justifications.get(*b"BEEF"), Some(&vec![]));
+    assert_eq!(justifications.get(*b"none"), None);
+}
+
+#[test]
+fn signed_block() {
+    let block = Block {
+        header: header(),
+        extrinsics: vec![],
+    };
+    let unjustified = SignedBlock {
+        block: block.clone(),
+        justifications: None,
+    };
+    assert_golden(&unjustified, &format!("{HEADER}0000"));
+
+    let justified = SignedBlock {
+        block,
+        justifications: Some((*b"FRNK", vec![1]).into()),
+    };
+    assert_golden(&justified, &format!("{HEADER}00010446524e4b0401"));
+}
@@ -0,0 +1,112 @@
+use chaindb_primitives::{
+    ConsensusEngineId, Digest, DigestError, DigestExtension, DigestItem, DigestRegistry, Hash,
+    Header,
+};
+
+const SLOT: ConsensusEngineId = *b"slot";
+
+/// Accepts pre-runtime slot numbers encoded as one byte and any seal whose
+/// payload is `[0xee]`.
+struct SlotDigest;
+
+impl DigestExtension for SlotDigest {
+    fn engine_id(&self) -> ConsensusEngineId {
+        SLOT
+    }
+
+    fn verify(&self, _header: &Header, item: &DigestItem) -> Result<(), String> {
+        match item {
+            DigestItem::PreRuntime(_, data) if data.len() == 1 => Ok(()),
+            DigestItem::Seal(_, data) if data[..] == [0xee] => Ok(()),
+            _ => Err("bad slot digest".into()),
+        }
+    }
+}
+
+fn header(logs: Vec<DigestItem>) -> Header {
+    Header::new(1, Hash::zero(), Hash::zero(), Hash::zero(), Digest { logs })
+}
+
+fn registry() -> DigestRegistry {
+    let mut registry = DigestRegistry::new();
+    registry.register(Box::new(SlotDigest)).unwrap();
+    registry
+}
+
+#[test]
+fn accessors() {
+    let mut digest = Digest::default();
+    digest.push(DigestItem::Other(vec![9]));
+    digest.push(DigestItem::PreRuntime(SLOT, vec![3]));
+    digest.push(DigestItem::Consensus(SLOT, vec![4]));
+    digest.push(DigestItem::Seal(SLOT, vec![0xee]));
+
+    assert_eq!(digest.pre_runtime(SLOT), Some(&[3][..]));
+    assert_eq!(digest.consensus(SLOT), Some(&[4][..]));
+    assert_eq!(digest.pre_runtime(*b"none"), None);
+    assert_eq!(digest.seal(), Some((SLOT, &[0xee][..])));
+
+    assert_eq!(digest.pop_seal(), Some(DigestItem::Seal(SLOT, vec![0xee])));
+    assert_eq!(digest.seal(), None);
+    assert_eq!(digest.pop_seal(), None);
+    assert_eq!(digest.logs().len(), 3);
+}
+
+#[test]
+fn duplicate_registration_is_rejected() {
+    let mut registry = registry();
+    assert_eq!(
+        registry.register(Box::new(SlotDigest)),
+        Err(DigestError::AlreadyRegistered(SLOT))
+    );
+    assert!(registry.get(SLOT).is_some());
+}
+
+#[test]
+fn verifies_registered_items() {
+    let header = header(vec![
+        DigestItem::Other(vec![1, 2, 3]),
+        DigestItem::PreRuntime(SLOT, vec![7]),
+        DigestItem::Seal(SLOT, vec![0xee]),
+    ]);
+    assert_eq!(registry().verify(&header), Ok(()));
+}
+
+#[test]
+fn rejects_unknown_engine() {
+    let header = header(vec![DigestItem::PreRuntime(*b"aura", vec![])]);
+    assert_eq!(
+        registry().verify(&header),
+        Err(DigestError::UnknownEngine(*b"aura"))
+    );
+}
+
+#[test]
+fn rejects_misplaced_seal() {
+    let header = header(vec![
+        DigestItem::Seal(SLOT, vec![0xee]),
+        DigestItem::PreRuntime(SLOT, vec![7]),
+    ]);
+    assert_eq!(
+        registry().verify(&header),
+        Err(DigestError::MisplacedSeal { index: 0 })
+    );
+}
+
+#[test]
+fn reports_extension_rejection() {
+    let header = header(vec![DigestItem::PreRuntime(SLOT, vec![])]);
+    let err = registry().verify(&header).unwrap_err();
+    assert_eq!(
+        err,
+        DigestError::Rejected {
+            engine_id: SLOT,
+            index: 0,
+            reason: "bad slot digest".into(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "engine \"slot\" rejected digest item 0: bad slot digest"
+    );
+}